@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::fmt;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum MatrixKind {
@@ -7,6 +8,301 @@ enum MatrixKind {
     Any,
 }
 
+/// A minimal, library-agnostic view of a PMML XML element.
+///
+/// Callers adapt whichever XML tree their parser produces (e.g. a DOM node,
+/// a `quick-xml` event stream collected into a tree, ...) into this shape
+/// before handing it to [`from_pmml`]. Attribute names and child element
+/// names are taken verbatim from the PMML schema.
+#[derive(Debug, Clone, Default)]
+struct PmmlNode {
+    name: String,
+    attributes: HashMap<String, String>,
+    children: Vec<PmmlNode>,
+    text: Option<String>,
+}
+
+impl PmmlNode {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(String::as_str)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a PmmlNode> {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+}
+
+/// Errors that can occur while deserializing a PMML `<Matrix>` element.
+#[derive(Debug, PartialEq)]
+enum MatrixParseError {
+    InvalidAttribute { attribute: &'static str, value: String },
+    UnknownKind(String),
+    MissingDimensions,
+    RaggedRow { row: usize, expected: usize, found: usize },
+    InvalidArrayEntry(String),
+    MissingCells,
+    InvalidCell(String),
+    DimensionMismatch { attribute: &'static str, declared: usize, actual: usize },
+}
+
+impl fmt::Display for MatrixParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixParseError::InvalidAttribute { attribute, value } => {
+                write!(f, "invalid value `{value}` for attribute `{attribute}`")
+            }
+            MatrixParseError::UnknownKind(kind) => write!(f, "unknown Matrix kind `{kind}`"),
+            MatrixParseError::MissingDimensions => {
+                write!(f, "sparse Matrix requires nbRows and nbCols")
+            }
+            MatrixParseError::RaggedRow { row, expected, found } => write!(
+                f,
+                "row {row} has {found} entries, expected {expected}"
+            ),
+            MatrixParseError::InvalidArrayEntry(value) => {
+                write!(f, "could not parse Array entry `{value}` as a number")
+            }
+            MatrixParseError::MissingCells => {
+                write!(f, "<Matrix> has neither <Array> nor <MatCell> children")
+            }
+            MatrixParseError::InvalidCell(reason) => write!(f, "invalid <MatCell>: {reason}"),
+            MatrixParseError::DimensionMismatch { attribute, declared, actual } => write!(
+                f,
+                "declared `{attribute}`={declared} does not match the {actual} actually parsed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MatrixParseError {}
+
+/// Parses a PMML `<Matrix>` element into the concrete [`Matrix`] implementation
+/// selected by its `kind` attribute.
+///
+/// - `kind="diagonal"` expects a single `<Array>` of diagonal entries and
+///   produces a [`DiagonalMatrix`].
+/// - `kind="symmetric"` expects ragged `<Array>` rows holding the lower
+///   triangle (row `i` has `i + 1` entries) and produces a [`SymmetricMatrix`].
+/// - `kind="any"` (or the attribute absent, per the PMML default) produces a
+///   [`DenseMatrix`] from full-width `<Array>` rows, or a [`SparseMatrix`]
+///   when the element instead carries `<MatCell row="" col="">` children.
+///
+/// If `nbRows`/`nbCols` are present, they're checked against the shape
+/// actually parsed from the element's children, regardless of `kind`.
+fn from_pmml(node: &PmmlNode) -> Result<Box<dyn Matrix>, MatrixParseError> {
+    let kind = node.attr("kind").unwrap_or("any");
+    let diag_default = parse_optional_f64(node, "diagDefault")?;
+    let off_diag_default = parse_optional_f64(node, "offDiagDefault")?;
+
+    match kind {
+        "diagonal" => {
+            let values = parse_single_array(node)?;
+            check_declared_dimensions(node, values.len(), values.len())?;
+            Ok(Box::new(DiagonalMatrix {
+                values,
+                off_diag_default,
+            }))
+        }
+        "symmetric" => {
+            let values = parse_ragged_rows(node)?;
+            check_declared_dimensions(node, values.len(), values.len())?;
+            Ok(Box::new(SymmetricMatrix { values }))
+        }
+        "any" => {
+            let cells: Vec<&PmmlNode> = node.children_named("MatCell").collect();
+            if !cells.is_empty() {
+                parse_sparse_cells(node, cells, diag_default, off_diag_default)
+            } else {
+                let values = parse_rectangular_rows(node)?;
+                check_declared_dimensions(node, values.len(), values[0].len())?;
+                Ok(Box::new(DenseMatrix { values }))
+            }
+        }
+        other => Err(MatrixParseError::UnknownKind(other.to_string())),
+    }
+}
+
+/// Validates a matrix's actual shape against the optional `nbRows`/`nbCols`
+/// attributes PMML allows on `<Matrix>`, catching the case where the
+/// declared dimensions don't match what the `<Array>` children actually
+/// contain. Absent attributes are not checked — only `kind="any"` with
+/// `<MatCell>` children requires them (see [`parse_sparse_cells`]).
+fn check_declared_dimensions(
+    node: &PmmlNode,
+    nb_rows_actual: usize,
+    nb_cols_actual: usize,
+) -> Result<(), MatrixParseError> {
+    if let Some(declared) = parse_optional_usize(node, "nbRows")? {
+        if declared != nb_rows_actual {
+            return Err(MatrixParseError::DimensionMismatch {
+                attribute: "nbRows",
+                declared,
+                actual: nb_rows_actual,
+            });
+        }
+    }
+    if let Some(declared) = parse_optional_usize(node, "nbCols")? {
+        if declared != nb_cols_actual {
+            return Err(MatrixParseError::DimensionMismatch {
+                attribute: "nbCols",
+                declared,
+                actual: nb_cols_actual,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn parse_optional_f64(node: &PmmlNode, attribute: &'static str) -> Result<Option<f64>, MatrixParseError> {
+    match node.attr(attribute) {
+        None => Ok(None),
+        Some(value) => value
+            .trim()
+            .parse::<f64>()
+            .map(Some)
+            .map_err(|_| MatrixParseError::InvalidAttribute {
+                attribute,
+                value: value.to_string(),
+            }),
+    }
+}
+
+fn parse_optional_usize(node: &PmmlNode, attribute: &'static str) -> Result<Option<usize>, MatrixParseError> {
+    match node.attr(attribute) {
+        None => Ok(None),
+        Some(value) => value
+            .trim()
+            .parse::<usize>()
+            .map(Some)
+            .map_err(|_| MatrixParseError::InvalidAttribute {
+                attribute,
+                value: value.to_string(),
+            }),
+    }
+}
+
+fn parse_array_text(node: &PmmlNode) -> Result<Vec<f64>, MatrixParseError> {
+    let text = node.text.as_deref().unwrap_or("");
+    text.split_whitespace()
+        .map(|token| {
+            token
+                .parse::<f64>()
+                .map_err(|_| MatrixParseError::InvalidArrayEntry(token.to_string()))
+        })
+        .collect()
+}
+
+fn parse_single_array(node: &PmmlNode) -> Result<Vec<f64>, MatrixParseError> {
+    let array = node
+        .children_named("Array")
+        .next()
+        .ok_or(MatrixParseError::MissingCells)?;
+    parse_array_text(array)
+}
+
+fn parse_ragged_rows(node: &PmmlNode) -> Result<Vec<Vec<f64>>, MatrixParseError> {
+    let mut rows = Vec::new();
+    for (i, array) in node.children_named("Array").enumerate() {
+        let row = parse_array_text(array)?;
+        if row.len() != i + 1 {
+            return Err(MatrixParseError::RaggedRow {
+                row: i,
+                expected: i + 1,
+                found: row.len(),
+            });
+        }
+        rows.push(row);
+    }
+    if rows.is_empty() {
+        return Err(MatrixParseError::MissingCells);
+    }
+    Ok(rows)
+}
+
+fn parse_rectangular_rows(node: &PmmlNode) -> Result<Vec<Vec<f64>>, MatrixParseError> {
+    let mut rows = Vec::new();
+    let mut width = None;
+    for array in node.children_named("Array") {
+        let row = parse_array_text(array)?;
+        match width {
+            None => width = Some(row.len()),
+            Some(expected) if expected != row.len() => {
+                return Err(MatrixParseError::RaggedRow {
+                    row: rows.len(),
+                    expected,
+                    found: row.len(),
+                });
+            }
+            _ => {}
+        }
+        rows.push(row);
+    }
+    if rows.is_empty() {
+        return Err(MatrixParseError::MissingCells);
+    }
+    Ok(rows)
+}
+
+fn parse_sparse_cells(
+    node: &PmmlNode,
+    cells: Vec<&PmmlNode>,
+    diag_default: Option<f64>,
+    off_diag_default: Option<f64>,
+) -> Result<Box<dyn Matrix>, MatrixParseError> {
+    let nb_rows = parse_optional_usize(node, "nbRows")?.ok_or(MatrixParseError::MissingDimensions)?;
+    let nb_cols = parse_optional_usize(node, "nbCols")?.ok_or(MatrixParseError::MissingDimensions)?;
+
+    let mut builder = CooBuilder::new(nb_rows, nb_cols, diag_default, off_diag_default);
+    for cell in cells {
+        let row = cell
+            .attr("row")
+            .ok_or_else(|| MatrixParseError::InvalidCell("missing `row`".to_string()))?
+            .parse::<usize>()
+            .map_err(|_| MatrixParseError::InvalidCell("`row` is not a non-negative integer".to_string()))?;
+        let col = cell
+            .attr("col")
+            .ok_or_else(|| MatrixParseError::InvalidCell("missing `col`".to_string()))?
+            .parse::<usize>()
+            .map_err(|_| MatrixParseError::InvalidCell("`col` is not a non-negative integer".to_string()))?;
+        let value = cell
+            .text
+            .as_deref()
+            .unwrap_or("")
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| MatrixParseError::InvalidCell(format!("value is not a number at ({row}, {col})")))?;
+        builder
+            .push(row, col, value)
+            .map_err(|e| MatrixParseError::InvalidCell(e.to_string()))?;
+    }
+
+    Ok(Box::new(
+        builder
+            .build_csc(CooDuplicatePolicy::Sum)
+            .map_err(|e| MatrixParseError::InvalidCell(e.to_string()))?,
+    ))
+}
+
+/// Returned by [`Matrix::mul_vec`] when the input vector's length does not
+/// match the matrix's column count.
+#[derive(Debug, PartialEq)]
+struct MatrixDimensionError {
+    expected: usize,
+    found: usize,
+}
+
+impl fmt::Display for MatrixDimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "vector has length {}, expected {} to match the matrix's column count",
+            self.found, self.expected
+        )
+    }
+}
+
+impl std::error::Error for MatrixDimensionError {}
+
 trait Matrix {
     fn get(&self, i: usize, j: usize) -> f64;
     fn default(&self) -> f64;
@@ -15,6 +311,39 @@ trait Matrix {
     fn nb_rows(&self) -> usize;
     fn nb_cols(&self) -> usize;
     fn kind(&self) -> MatrixKind;
+
+    /// Computes `y = M . x` for PMML regression/scorecard evaluation.
+    ///
+    /// The default implementation is the straightforward O(rows * cols)
+    /// dense loop; [`DiagonalMatrix`] and [`SparseMatrix`] override it with a
+    /// sparsity-aware pass that skips the entries their representation
+    /// doesn't store, falling back to this default whenever a non-zero
+    /// declared default fill value means the unstored entries also
+    /// contribute to the product. [`SymmetricMatrix`] overrides it
+    /// unconditionally since it never has a non-zero default to worry about.
+    fn mul_vec(&self, x: &[f64]) -> Result<Vec<f64>, MatrixDimensionError> {
+        dense_mul_vec(self, x)
+    }
+}
+
+/// Shared O(rows * cols) implementation behind [`Matrix::mul_vec`]'s default
+/// and the sparsity-aware overrides' fallback when a non-zero declared
+/// default means every entry, not just the stored ones, contributes.
+fn dense_mul_vec<M: Matrix + ?Sized>(matrix: &M, x: &[f64]) -> Result<Vec<f64>, MatrixDimensionError> {
+    if x.len() != matrix.nb_cols() {
+        return Err(MatrixDimensionError {
+            expected: matrix.nb_cols(),
+            found: x.len(),
+        });
+    }
+
+    let mut y = vec![0.0; matrix.nb_rows()];
+    for (i, y_i) in y.iter_mut().enumerate() {
+        for (j, &x_j) in x.iter().enumerate() {
+            *y_i += matrix.get(i, j) * x_j;
+        }
+    }
+    Ok(y)
 }
 
 #[derive(Debug)]
@@ -26,7 +355,7 @@ struct DiagonalMatrix {
 impl Matrix for DiagonalMatrix {
     fn get(&self, i: usize, j: usize) -> f64 {
         if i == j {
-            self.values[i]
+            self.values.get(i).copied().unwrap_or(self.default())
         } else {
             self.off_diag_default.unwrap_or(self.default())
         }
@@ -55,6 +384,22 @@ impl Matrix for DiagonalMatrix {
     fn kind(&self) -> MatrixKind {
         MatrixKind::Diagonal
     }
+
+    fn mul_vec(&self, x: &[f64]) -> Result<Vec<f64>, MatrixDimensionError> {
+        if self.off_diag_default.unwrap_or(0.0) != 0.0 {
+            // A non-zero off-diagonal default contributes to every
+            // off-diagonal entry, so the diagonal-only shortcut below would
+            // silently drop it; fall back to the dense computation instead.
+            return dense_mul_vec(self, x);
+        }
+        if x.len() != self.nb_cols() {
+            return Err(MatrixDimensionError {
+                expected: self.nb_cols(),
+                found: x.len(),
+            });
+        }
+        Ok(self.values.iter().zip(x).map(|(v, x_i)| v * x_i).collect())
+    }
 }
 
 #[derive(Debug)]
@@ -71,19 +416,10 @@ impl Matrix for SymmetricMatrix {
             return self.default();
         }
 
-        let inner_len = self.values[i].len();
-
-        if i > j {
-            if i < self.values[j].len() {
-                return self.values[j][i];
-            }
-        } else {
-            if j < inner_len {
-                return self.values[i][j];
-            }
-        }
-
-        self.default()
+        // Only the lower triangle is stored: row `r` holds columns `0..=r`.
+        // Reflect any upper-triangle query onto its stored mirror entry.
+        let (row, col) = if i >= j { (i, j) } else { (j, i) };
+        self.values[row][col]
     }
 
     fn default(&self) -> f64 {
@@ -109,6 +445,26 @@ impl Matrix for SymmetricMatrix {
     fn kind(&self) -> MatrixKind {
         MatrixKind::Symmetric
     }
+
+    fn mul_vec(&self, x: &[f64]) -> Result<Vec<f64>, MatrixDimensionError> {
+        if x.len() != self.nb_cols() {
+            return Err(MatrixDimensionError {
+                expected: self.nb_cols(),
+                found: x.len(),
+            });
+        }
+
+        let mut y = vec![0.0; self.nb_rows()];
+        for (i, row) in self.values.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                y[i] += value * x[j];
+                if j != i {
+                    y[j] += value * x[i];
+                }
+            }
+        }
+        Ok(y)
+    }
 }
 
 #[derive(Debug)]
@@ -164,11 +520,110 @@ struct SparseMatrix {
     off_diag_default: Option<f64>,
 }
 
+/// Errors returned by [`SparseMatrix::new_csc`] when the CSC arrays don't
+/// describe a well-formed matrix.
+#[derive(Debug, PartialEq)]
+enum SparseMatrixError {
+    ColPtrsWrongLength { expected: usize, found: usize },
+    ColPtrsNotNondecreasing { col: usize },
+    RowIndexOutOfBounds { row: usize, nb_rows: usize },
+    RowIndicesNotSortedWithinColumn { col: usize },
+    ValuesLengthMismatch { row_indices: usize, values: usize },
+}
+
+impl fmt::Display for SparseMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SparseMatrixError::ColPtrsWrongLength { expected, found } => {
+                write!(f, "col_ptrs has length {found}, expected {expected} (nb_cols + 1)")
+            }
+            SparseMatrixError::ColPtrsNotNondecreasing { col } => {
+                write!(f, "col_ptrs is decreasing or runs past row_indices at column {col}")
+            }
+            SparseMatrixError::RowIndexOutOfBounds { row, nb_rows } => {
+                write!(f, "row index {row} is out of bounds for {nb_rows} rows")
+            }
+            SparseMatrixError::RowIndicesNotSortedWithinColumn { col } => {
+                write!(f, "row_indices is not sorted ascending within column {col}")
+            }
+            SparseMatrixError::ValuesLengthMismatch { row_indices, values } => write!(
+                f,
+                "values has length {values}, expected {row_indices} to match row_indices"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SparseMatrixError {}
+
 impl SparseMatrix {
+    /// Builds a [`SparseMatrix`] from raw CSC arrays, validating the
+    /// invariants that [`SparseMatrix::index`]'s binary search relies on:
+    /// `col_ptrs` has `nb_cols + 1` non-decreasing entries, every row index
+    /// is in bounds and sorted ascending within its column, and `values`
+    /// matches `row_indices` in length.
+    fn new_csc(
+        nb_rows: usize,
+        nb_cols: usize,
+        col_ptrs: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<f64>,
+        diag_default: Option<f64>,
+        off_diag_default: Option<f64>,
+    ) -> Result<Self, SparseMatrixError> {
+        if col_ptrs.len() != nb_cols + 1 {
+            return Err(SparseMatrixError::ColPtrsWrongLength {
+                expected: nb_cols + 1,
+                found: col_ptrs.len(),
+            });
+        }
+        if row_indices.len() != values.len() {
+            return Err(SparseMatrixError::ValuesLengthMismatch {
+                row_indices: row_indices.len(),
+                values: values.len(),
+            });
+        }
+        for col in 0..nb_cols {
+            if col_ptrs[col] > col_ptrs[col + 1] || col_ptrs[col + 1] > row_indices.len() {
+                return Err(SparseMatrixError::ColPtrsNotNondecreasing { col });
+            }
+            let column = &row_indices[col_ptrs[col]..col_ptrs[col + 1]];
+            if !column.windows(2).all(|w| w[0] < w[1]) {
+                return Err(SparseMatrixError::RowIndicesNotSortedWithinColumn { col });
+            }
+            if let Some(&row) = column.last() {
+                if row >= nb_rows {
+                    return Err(SparseMatrixError::RowIndexOutOfBounds { row, nb_rows });
+                }
+            }
+        }
+
+        Ok(SparseMatrix {
+            nb_rows,
+            nb_cols,
+            col_ptrs,
+            row_indices,
+            values,
+            diag_default,
+            off_diag_default,
+        })
+    }
+
+    /// Looks up the position of `(i, j)` in `values`/`row_indices`.
+    ///
+    /// Relies on the CSC sorted-row invariant enforced by
+    /// [`SparseMatrix::new_csc`]: `row_indices` within each column is
+    /// ascending, so a binary search replaces the naive O(nnz_per_col) scan.
+    /// Returns `None` (rather than panicking) for `i`/`j` outside the
+    /// matrix's declared shape, matching every other `Matrix` impl.
     fn index(&self, i: usize, j: usize) -> Option<usize> {
-        self.row_indices[self.col_ptrs[j]..self.col_ptrs[j + 1]]
-            .iter()
-            .position(|&row| row == i)
+        if i >= self.nb_rows || j >= self.nb_cols {
+            return None;
+        }
+        let column = &self.row_indices[self.col_ptrs[j]..self.col_ptrs[j + 1]];
+        column
+            .binary_search(&i)
+            .ok()
             .map(|pos| self.col_ptrs[j] + pos)
     }
 }
@@ -210,8 +665,194 @@ impl Matrix for SparseMatrix {
     fn kind(&self) -> MatrixKind {
         MatrixKind::Any
     }
+
+    fn mul_vec(&self, x: &[f64]) -> Result<Vec<f64>, MatrixDimensionError> {
+        if self.diag_default.unwrap_or(0.0) != 0.0 || self.off_diag_default.unwrap_or(0.0) != 0.0 {
+            // A non-zero declared default contributes to every entry the CSC
+            // arrays don't store, so the stored-entries-only loop below
+            // would silently drop it; fall back to the dense computation.
+            return dense_mul_vec(self, x);
+        }
+        if x.len() != self.nb_cols() {
+            return Err(MatrixDimensionError {
+                expected: self.nb_cols(),
+                found: x.len(),
+            });
+        }
+
+        let mut y = vec![0.0; self.nb_rows()];
+        for (j, &x_j) in x.iter().enumerate() {
+            for k in self.col_ptrs[j]..self.col_ptrs[j + 1] {
+                y[self.row_indices[k]] += self.values[k] * x_j;
+            }
+        }
+        Ok(y)
+    }
 }
 
+/// What to do when a [`CooBuilder`] receives two triplets at the same
+/// `(row, col)` coordinate.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum CooDuplicatePolicy {
+    /// Add the values together, as most sparse libraries do for accumulation.
+    Sum,
+    /// Keep the first value seen and ignore the rest.
+    KeepFirst,
+    /// Treat a duplicate coordinate as malformed input.
+    Reject,
+}
+
+/// Errors that can occur while accumulating or assembling a [`CooBuilder`].
+#[derive(Debug, PartialEq)]
+enum CooError {
+    OutOfBounds { row: usize, col: usize, nb_rows: usize, nb_cols: usize },
+    DuplicateCoordinate { row: usize, col: usize },
+}
+
+impl fmt::Display for CooError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CooError::OutOfBounds { row, col, nb_rows, nb_cols } => write!(
+                f,
+                "coordinate ({row}, {col}) is out of bounds for a {nb_rows}x{nb_cols} matrix"
+            ),
+            CooError::DuplicateCoordinate { row, col } => {
+                write!(f, "duplicate entry at ({row}, {col})")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CooError {}
+
+/// Accumulates `(row, col, value)` triplets and assembles them into a
+/// [`SparseMatrix`] stored in CSC layout, mirroring the `convert_coo_csr`
+/// style of conversion used by `nalgebra-sparse`.
+#[derive(Debug)]
+struct CooBuilder {
+    nb_rows: usize,
+    nb_cols: usize,
+    diag_default: Option<f64>,
+    off_diag_default: Option<f64>,
+    triplets: Vec<(usize, usize, f64)>,
+}
+
+impl CooBuilder {
+    fn new(nb_rows: usize, nb_cols: usize, diag_default: Option<f64>, off_diag_default: Option<f64>) -> Self {
+        CooBuilder {
+            nb_rows,
+            nb_cols,
+            diag_default,
+            off_diag_default,
+            triplets: Vec::new(),
+        }
+    }
+
+    /// Records a triplet, rejecting coordinates outside the declared shape.
+    fn push(&mut self, row: usize, col: usize, value: f64) -> Result<(), CooError> {
+        if row >= self.nb_rows || col >= self.nb_cols {
+            return Err(CooError::OutOfBounds {
+                row,
+                col,
+                nb_rows: self.nb_rows,
+                nb_cols: self.nb_cols,
+            });
+        }
+        self.triplets.push((row, col, value));
+        Ok(())
+    }
+
+    /// Builds the CSC representation, sorting triplets by column (then row)
+    /// and resolving duplicate coordinates per `policy`.
+    fn build_csc(mut self, policy: CooDuplicatePolicy) -> Result<SparseMatrix, CooError> {
+        self.triplets.sort_by_key(|&(row, col, _)| (col, row));
+
+        let mut row_indices = Vec::with_capacity(self.triplets.len());
+        let mut cols = Vec::with_capacity(self.triplets.len());
+        let mut values = Vec::with_capacity(self.triplets.len());
+        let mut iter = self.triplets.into_iter().peekable();
+
+        while let Some((row, col, value)) = iter.next() {
+            let mut value = value;
+            while let Some(&(next_row, next_col, next_value)) = iter.peek() {
+                if next_row != row || next_col != col {
+                    break;
+                }
+                match policy {
+                    CooDuplicatePolicy::Sum => value += next_value,
+                    CooDuplicatePolicy::KeepFirst => {}
+                    CooDuplicatePolicy::Reject => {
+                        return Err(CooError::DuplicateCoordinate { row, col });
+                    }
+                }
+                iter.next();
+            }
+            row_indices.push(row);
+            cols.push(col);
+            values.push(value);
+        }
+
+        // `cols` is sorted (triplets were sorted by column before merging),
+        // so a counting pass over it yields the CSC column offsets.
+        let mut col_ptrs = vec![0usize; self.nb_cols + 1];
+        for &col in &cols {
+            col_ptrs[col + 1] += 1;
+        }
+        for col in 0..self.nb_cols {
+            col_ptrs[col + 1] += col_ptrs[col];
+        }
+
+        // Route through the same validation `new_csc` applies to
+        // caller-supplied CSC arrays, rather than trusting this pass's
+        // bookkeeping blindly. The counting-sort above guarantees the
+        // invariants hold, so a violation here means this function has a
+        // bug, not that the input was malformed.
+        Ok(SparseMatrix::new_csc(
+            self.nb_rows,
+            self.nb_cols,
+            col_ptrs,
+            row_indices,
+            values,
+            self.diag_default,
+            self.off_diag_default,
+        )
+        .expect("CooBuilder always produces a well-formed CSC layout"))
+    }
+}
+
+/// Converts any [`Matrix`] into a [`DenseMatrix`] by materializing every
+/// `(i, j)` entry via [`Matrix::get`].
+fn to_dense(matrix: &dyn Matrix) -> DenseMatrix {
+    let nb_rows = matrix.nb_rows();
+    let nb_cols = matrix.nb_cols();
+    let values = (0..nb_rows)
+        .map(|i| (0..nb_cols).map(|j| matrix.get(i, j)).collect())
+        .collect();
+    DenseMatrix { values }
+}
+
+impl DenseMatrix {
+    /// Converts this dense matrix into a [`SparseMatrix`], dropping entries
+    /// whose absolute value is at or below `threshold`.
+    fn to_sparse(&self, threshold: f64) -> SparseMatrix {
+        let nb_rows = self.nb_rows();
+        let nb_cols = self.nb_cols();
+        let mut builder = CooBuilder::new(nb_rows, nb_cols, None, None);
+        for i in 0..nb_rows {
+            for j in 0..nb_cols {
+                let value = self.get(i, j);
+                if value.abs() > threshold {
+                    builder
+                        .push(i, j, value)
+                        .expect("indices are within the matrix's own bounds");
+                }
+            }
+        }
+        builder
+            .build_csc(CooDuplicatePolicy::KeepFirst)
+            .expect("dense iteration never produces duplicate coordinates")
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -239,12 +880,12 @@ mod tests {
         };
 
         assert_eq!(symmetric_matrix.get(0, 0), 1.0);
-        // assert_eq!(symmetric_matrix.get(1, 1), 3.0);
-        // assert_eq!(symmetric_matrix.get(0, 1), 2.0);
-        // assert_eq!(symmetric_matrix.get(1, 0), 2.0);
-        // assert_eq!(symmetric_matrix.nb_rows(), 3);
-        // assert_eq!(symmetric_matrix.nb_cols(), 3);
-        // assert_eq!(symmetric_matrix.kind(), MatrixKind::Symmetric);
+        assert_eq!(symmetric_matrix.get(1, 1), 3.0);
+        assert_eq!(symmetric_matrix.get(0, 1), 2.0);
+        assert_eq!(symmetric_matrix.get(1, 0), 2.0);
+        assert_eq!(symmetric_matrix.nb_rows(), 3);
+        assert_eq!(symmetric_matrix.nb_cols(), 3);
+        assert_eq!(symmetric_matrix.kind(), MatrixKind::Symmetric);
     }
 
     #[test]
@@ -282,4 +923,623 @@ mod tests {
         assert_eq!(sparse_matrix.nb_cols(), 3);
         assert_eq!(sparse_matrix.kind(), MatrixKind::Any);
     }
+
+    fn array_node(values: &[f64]) -> PmmlNode {
+        let text = values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        PmmlNode {
+            name: "Array".to_string(),
+            text: Some(text),
+            ..Default::default()
+        }
+    }
+
+    fn matrix_node(kind: &str, children: Vec<PmmlNode>) -> PmmlNode {
+        let mut attributes = HashMap::new();
+        attributes.insert("kind".to_string(), kind.to_string());
+        PmmlNode {
+            name: "Matrix".to_string(),
+            attributes,
+            children,
+            text: None,
+        }
+    }
+
+    fn matrix_node_with_dims(kind: &str, nb_rows: usize, nb_cols: usize, children: Vec<PmmlNode>) -> PmmlNode {
+        let mut node = matrix_node(kind, children);
+        node.attributes.insert("nbRows".to_string(), nb_rows.to_string());
+        node.attributes.insert("nbCols".to_string(), nb_cols.to_string());
+        node
+    }
+
+    #[test]
+    fn from_pmml_parses_diagonal_matrix() {
+        let node = matrix_node("diagonal", vec![array_node(&[1.0, 2.0, 3.0])]);
+
+        let matrix = from_pmml(&node).unwrap();
+
+        assert_eq!(matrix.kind(), MatrixKind::Diagonal);
+        assert_eq!(matrix.get(1, 1), 2.0);
+        assert_eq!(matrix.get(0, 1), 0.0);
+    }
+
+    #[test]
+    fn from_pmml_accepts_diagonal_matrix_with_matching_declared_dimensions() {
+        let node = matrix_node_with_dims("diagonal", 3, 3, vec![array_node(&[1.0, 2.0, 3.0])]);
+
+        let matrix = from_pmml(&node).unwrap();
+
+        assert_eq!(matrix.get(1, 1), 2.0);
+    }
+
+    #[test]
+    fn from_pmml_rejects_diagonal_matrix_with_mismatched_declared_dimensions() {
+        let node = matrix_node_with_dims("diagonal", 5, 5, vec![array_node(&[1.0, 2.0, 3.0])]);
+
+        let Err(err) = from_pmml(&node) else {
+            panic!("expected a dimension mismatch error");
+        };
+
+        assert_eq!(
+            err,
+            MatrixParseError::DimensionMismatch {
+                attribute: "nbRows",
+                declared: 5,
+                actual: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn from_pmml_parses_symmetric_lower_triangle() {
+        let node = matrix_node(
+            "symmetric",
+            vec![
+                array_node(&[1.0]),
+                array_node(&[2.0, 3.0]),
+                array_node(&[4.0, 5.0, 6.0]),
+            ],
+        );
+
+        let matrix = from_pmml(&node).unwrap();
+
+        assert_eq!(matrix.kind(), MatrixKind::Symmetric);
+        assert_eq!(matrix.get(2, 2), 6.0);
+        assert_eq!(matrix.get(2, 0), 4.0);
+        assert_eq!(matrix.get(0, 2), 4.0);
+        assert_eq!(matrix.nb_rows(), 3);
+    }
+
+    #[test]
+    fn from_pmml_rejects_ragged_symmetric_row() {
+        let node = matrix_node("symmetric", vec![array_node(&[1.0]), array_node(&[2.0])]);
+
+        let Err(err) = from_pmml(&node) else {
+            panic!("expected a ragged row error");
+        };
+
+        assert_eq!(
+            err,
+            MatrixParseError::RaggedRow {
+                row: 1,
+                expected: 2,
+                found: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn from_pmml_parses_dense_matrix_by_default() {
+        let node = matrix_node("any", vec![array_node(&[1.0, 2.0]), array_node(&[3.0, 4.0])]);
+
+        let matrix = from_pmml(&node).unwrap();
+
+        assert_eq!(matrix.kind(), MatrixKind::Any);
+        assert_eq!(matrix.get(1, 0), 3.0);
+    }
+
+    #[test]
+    fn from_pmml_rejects_dense_matrix_with_mismatched_declared_dimensions() {
+        let node = matrix_node_with_dims(
+            "any",
+            2,
+            3,
+            vec![array_node(&[1.0, 2.0]), array_node(&[3.0, 4.0])],
+        );
+
+        let Err(err) = from_pmml(&node) else {
+            panic!("expected a dimension mismatch error");
+        };
+
+        assert_eq!(
+            err,
+            MatrixParseError::DimensionMismatch {
+                attribute: "nbCols",
+                declared: 3,
+                actual: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn from_pmml_parses_sparse_cells() {
+        let mut attributes = HashMap::new();
+        attributes.insert("kind".to_string(), "any".to_string());
+        attributes.insert("nbRows".to_string(), "2".to_string());
+        attributes.insert("nbCols".to_string(), "2".to_string());
+
+        let mut cell_attrs = HashMap::new();
+        cell_attrs.insert("row".to_string(), "1".to_string());
+        cell_attrs.insert("col".to_string(), "0".to_string());
+        let cell = PmmlNode {
+            name: "MatCell".to_string(),
+            attributes: cell_attrs,
+            text: Some("5.0".to_string()),
+            ..Default::default()
+        };
+
+        let node = PmmlNode {
+            name: "Matrix".to_string(),
+            attributes,
+            children: vec![cell],
+            text: None,
+        };
+
+        let matrix = from_pmml(&node).unwrap();
+
+        assert_eq!(matrix.get(1, 0), 5.0);
+        assert_eq!(matrix.get(0, 0), 0.0);
+    }
+
+    #[test]
+    fn from_pmml_rejects_unknown_kind() {
+        let node = matrix_node("banded", vec![array_node(&[1.0])]);
+
+        let Err(err) = from_pmml(&node) else {
+            panic!("expected an unknown-kind error");
+        };
+
+        assert_eq!(err, MatrixParseError::UnknownKind("banded".to_string()));
+    }
+
+    #[test]
+    fn coo_builder_sums_duplicate_coordinates() {
+        let mut builder = CooBuilder::new(2, 2, None, None);
+        builder.push(0, 1, 2.0).unwrap();
+        builder.push(0, 1, 3.0).unwrap();
+        builder.push(1, 0, 4.0).unwrap();
+
+        let sparse = builder.build_csc(CooDuplicatePolicy::Sum).unwrap();
+
+        assert_eq!(sparse.get(0, 1), 5.0);
+        assert_eq!(sparse.get(1, 0), 4.0);
+    }
+
+    #[test]
+    fn coo_builder_rejects_duplicate_coordinates_when_asked() {
+        let mut builder = CooBuilder::new(2, 2, None, None);
+        builder.push(0, 0, 1.0).unwrap();
+        builder.push(0, 0, 2.0).unwrap();
+
+        let Err(err) = builder.build_csc(CooDuplicatePolicy::Reject) else {
+            panic!("expected a duplicate-coordinate error");
+        };
+        assert_eq!(err, CooError::DuplicateCoordinate { row: 0, col: 0 });
+    }
+
+    #[test]
+    fn coo_builder_rejects_out_of_bounds_coordinates() {
+        let mut builder = CooBuilder::new(2, 2, None, None);
+
+        assert_eq!(
+            builder.push(2, 0, 1.0),
+            Err(CooError::OutOfBounds { row: 2, col: 0, nb_rows: 2, nb_cols: 2 })
+        );
+    }
+
+    #[test]
+    fn to_dense_materializes_every_entry() {
+        let diagonal_matrix = DiagonalMatrix {
+            values: vec![1.0, 2.0, 3.0],
+            off_diag_default: Some(0.0),
+        };
+
+        let dense = to_dense(&diagonal_matrix);
+
+        assert_eq!(dense.get(0, 0), 1.0);
+        assert_eq!(dense.get(1, 1), 2.0);
+        assert_eq!(dense.get(2, 0), 0.0);
+        assert_eq!(dense.nb_rows(), 3);
+        assert_eq!(dense.nb_cols(), 3);
+    }
+
+    #[test]
+    fn dense_to_sparse_drops_near_zero_entries() {
+        let dense = DenseMatrix {
+            values: vec![vec![1.0, 0.0], vec![1e-12, 2.0]],
+        };
+
+        let sparse = dense.to_sparse(1e-9);
+
+        assert_eq!(sparse.get(0, 0), 1.0);
+        assert_eq!(sparse.get(0, 1), 0.0);
+        assert_eq!(sparse.get(1, 0), 0.0);
+        assert_eq!(sparse.get(1, 1), 2.0);
+    }
+
+    #[test]
+    fn dense_sparse_dense_round_trip_preserves_entries() {
+        let original = DenseMatrix {
+            values: vec![vec![1.0, 0.0, 3.0], vec![0.0, 5.0, 0.0]],
+        };
+
+        let sparse = original.to_sparse(0.0);
+        let round_tripped = to_dense(&sparse);
+
+        for i in 0..original.nb_rows() {
+            for j in 0..original.nb_cols() {
+                assert_eq!(original.get(i, j), round_tripped.get(i, j));
+            }
+        }
+    }
+
+    #[test]
+    fn diagonal_mul_vec() {
+        let diagonal_matrix = DiagonalMatrix {
+            values: vec![1.0, 2.0, 3.0],
+            off_diag_default: Some(0.0),
+        };
+
+        assert_eq!(
+            diagonal_matrix.mul_vec(&[1.0, 1.0, 1.0]).unwrap(),
+            vec![1.0, 2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn symmetric_mul_vec_uses_both_triangles() {
+        let symmetric_matrix = SymmetricMatrix {
+            values: vec![vec![1.0], vec![2.0, 3.0]],
+        };
+        // Full matrix is [[1, 2], [2, 3]].
+
+        assert_eq!(symmetric_matrix.mul_vec(&[1.0, 1.0]).unwrap(), vec![3.0, 5.0]);
+    }
+
+    #[test]
+    fn dense_mul_vec_matches_full_loop() {
+        let dense_matrix = DenseMatrix {
+            values: vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+        };
+
+        assert_eq!(dense_matrix.mul_vec(&[1.0, 1.0]).unwrap(), vec![3.0, 7.0]);
+    }
+
+    #[test]
+    fn sparse_mul_vec_ignores_zero_defaults() {
+        let sparse_matrix = SparseMatrix {
+            nb_rows: 2,
+            nb_cols: 2,
+            col_ptrs: vec![0, 1, 2],
+            row_indices: vec![0, 1],
+            values: vec![2.0, 3.0],
+            diag_default: None,
+            off_diag_default: Some(0.0),
+        };
+
+        assert_eq!(sparse_matrix.mul_vec(&[1.0, 1.0]).unwrap(), vec![2.0, 3.0]);
+    }
+
+    #[test]
+    fn sparse_mul_vec_honors_non_zero_off_diag_default() {
+        let sparse_matrix = SparseMatrix {
+            nb_rows: 2,
+            nb_cols: 2,
+            col_ptrs: vec![0, 1, 2],
+            row_indices: vec![0, 1],
+            values: vec![2.0, 3.0],
+            diag_default: None,
+            off_diag_default: Some(100.0),
+        };
+        // Full matrix is [[2, 100], [100, 3]]: the off-diagonal default
+        // contributes to every unstored entry, not just the stored diagonal.
+
+        assert_eq!(sparse_matrix.mul_vec(&[1.0, 1.0]).unwrap(), vec![102.0, 103.0]);
+    }
+
+    #[test]
+    fn diagonal_mul_vec_honors_non_zero_off_diag_default() {
+        let diagonal_matrix = DiagonalMatrix {
+            values: vec![1.0, 2.0],
+            off_diag_default: Some(5.0),
+        };
+        // Full matrix is [[1, 5], [5, 2]].
+
+        assert_eq!(diagonal_matrix.mul_vec(&[1.0, 1.0]).unwrap(), vec![6.0, 7.0]);
+    }
+
+    #[test]
+    fn mul_vec_rejects_length_mismatch() {
+        let diagonal_matrix = DiagonalMatrix {
+            values: vec![1.0, 2.0],
+            off_diag_default: None,
+        };
+
+        assert_eq!(
+            diagonal_matrix.mul_vec(&[1.0]),
+            Err(MatrixDimensionError {
+                expected: 2,
+                found: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn new_csc_accepts_well_formed_arrays() {
+        let sparse = SparseMatrix::new_csc(
+            3,
+            3,
+            vec![0, 1, 2, 3],
+            vec![0, 1, 2],
+            vec![1.0, 2.0, 3.0],
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(sparse.get(1, 1), 2.0);
+    }
+
+    #[test]
+    fn new_csc_rejects_wrong_length_col_ptrs() {
+        let err = SparseMatrix::new_csc(3, 3, vec![0, 1, 2], vec![0, 1], vec![1.0, 2.0], None, None)
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            SparseMatrixError::ColPtrsWrongLength {
+                expected: 4,
+                found: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn new_csc_rejects_col_ptrs_overrunning_row_indices() {
+        let err = SparseMatrix::new_csc(
+            2,
+            3,
+            vec![0, 1, 2, 100],
+            vec![0, 1, 2],
+            vec![1.0, 2.0, 3.0],
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err, SparseMatrixError::ColPtrsNotNondecreasing { col: 2 });
+    }
+
+    #[test]
+    fn new_csc_rejects_unsorted_rows_within_a_column() {
+        let err = SparseMatrix::new_csc(
+            3,
+            1,
+            vec![0, 3],
+            vec![2, 0, 1],
+            vec![1.0, 2.0, 3.0],
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err, SparseMatrixError::RowIndicesNotSortedWithinColumn { col: 0 });
+    }
+
+    #[test]
+    fn new_csc_rejects_out_of_bounds_row() {
+        let err = SparseMatrix::new_csc(2, 1, vec![0, 1], vec![5], vec![1.0], None, None).unwrap_err();
+
+        assert_eq!(err, SparseMatrixError::RowIndexOutOfBounds { row: 5, nb_rows: 2 });
+    }
+
+    #[test]
+    fn new_csc_rejects_values_length_mismatch() {
+        let err = SparseMatrix::new_csc(2, 1, vec![0, 1], vec![0], vec![1.0, 2.0], None, None).unwrap_err();
+
+        assert_eq!(
+            err,
+            SparseMatrixError::ValuesLengthMismatch {
+                row_indices: 1,
+                values: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn index_binary_searches_sorted_column() {
+        let sparse = SparseMatrix::new_csc(
+            5,
+            1,
+            vec![0, 4],
+            vec![0, 1, 3, 4],
+            vec![10.0, 20.0, 30.0, 40.0],
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(sparse.index(3, 0), Some(2));
+        assert_eq!(sparse.index(2, 0), None);
+    }
+
+    #[test]
+    fn get_returns_default_for_out_of_bounds_column_instead_of_panicking() {
+        let sparse = SparseMatrix::new_csc(2, 2, vec![0, 1, 2], vec![0, 1], vec![1.0, 2.0], None, None)
+            .unwrap();
+
+        assert_eq!(sparse.get(0, 2), sparse.default());
+        assert_eq!(sparse.get(2, 0), sparse.default());
+    }
+}
+
+/// Arbitrary generators and cross-cutting invariant checks for the matrix
+/// types, in the spirit of nalgebra's proptest strategies. Gated behind the
+/// `proptest-support` feature since `proptest` is a dev-only, opt-in
+/// dependency that most consumers of this crate never need.
+///
+/// This file ships as a standalone module with no manifest of its own, so
+/// activating this feature is the consuming crate's responsibility: declare
+/// `proptest` as an optional dev-dependency and a `proptest-support` feature
+/// that enables it. Until that's done in the crate this module is vendored
+/// into, this module is compiled out entirely and these tests do not run.
+#[cfg(all(test, feature = "proptest-support"))]
+mod proptest_support {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const MAX_DIM: usize = 8;
+    const VALUE_RANGE: std::ops::Range<f64> = -100.0..100.0;
+
+    /// Shrinks toward 1x1 matrices, then toward all-default values.
+    fn arbitrary_diagonal_matrix() -> impl Strategy<Value = DiagonalMatrix> {
+        (1..=MAX_DIM).prop_flat_map(|n| {
+            (
+                vec(VALUE_RANGE, n),
+                prop::option::of(VALUE_RANGE),
+            )
+                .prop_map(|(values, off_diag_default)| DiagonalMatrix {
+                    values,
+                    off_diag_default,
+                })
+        })
+    }
+
+    /// Shrinks toward 1x1 matrices; each row `r` holds `r + 1` entries,
+    /// which is the ragged lower-triangle layout PMML stores on the wire.
+    /// Generated as one flat vec (so the strategy stays homogeneous) and
+    /// then chunked into the ragged rows.
+    fn arbitrary_symmetric_matrix() -> impl Strategy<Value = SymmetricMatrix> {
+        (1..=MAX_DIM).prop_flat_map(|n| {
+            let total_entries = n * (n + 1) / 2;
+            vec(VALUE_RANGE, total_entries).prop_map(move |flat| {
+                let mut entries = flat.into_iter();
+                let values = (0..n).map(|r| entries.by_ref().take(r + 1).collect()).collect();
+                SymmetricMatrix { values }
+            })
+        })
+    }
+
+    fn arbitrary_dense_matrix() -> impl Strategy<Value = DenseMatrix> {
+        (1..=MAX_DIM, 1..=MAX_DIM).prop_flat_map(|(rows, cols)| {
+            vec(vec(VALUE_RANGE, cols), rows).prop_map(|values| DenseMatrix { values })
+        })
+    }
+
+    /// Builds a sparse matrix by generating a dense one first and dropping
+    /// every entry whose absolute value is at or below `threshold`, which guarantees a valid, sorted CSC
+    /// layout without duplicating the COO-assembly logic under test.
+    fn arbitrary_sparse_matrix() -> impl Strategy<Value = SparseMatrix> {
+        (
+            arbitrary_dense_matrix(),
+            0.0..50.0,
+            prop::option::of(VALUE_RANGE),
+            prop::option::of(VALUE_RANGE),
+        )
+            .prop_map(|(dense, threshold, diag_default, off_diag_default)| {
+                let mut sparse = dense.to_sparse(threshold);
+                sparse.diag_default = diag_default;
+                sparse.off_diag_default = off_diag_default;
+                sparse
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn symmetric_get_is_symmetric(matrix in arbitrary_symmetric_matrix()) {
+            let n = matrix.nb_rows();
+            for i in 0..n {
+                for j in 0..n {
+                    prop_assert_eq!(matrix.get(i, j), matrix.get(j, i));
+                }
+            }
+        }
+
+        #[test]
+        fn out_of_bounds_access_returns_default_not_panic(matrix in arbitrary_dense_matrix()) {
+            let rows = matrix.nb_rows();
+            let cols = matrix.nb_cols();
+            prop_assert_eq!(matrix.get(rows, 0), matrix.default());
+            prop_assert_eq!(matrix.get(0, cols), matrix.default());
+        }
+
+        #[test]
+        fn diagonal_out_of_bounds_access_returns_default_not_panic(matrix in arbitrary_diagonal_matrix()) {
+            let n = matrix.nb_rows();
+            // On-diagonal, beyond the stored values.
+            prop_assert_eq!(matrix.get(n, n), matrix.default());
+            // Off-diagonal, beyond the stored values.
+            prop_assert_eq!(matrix.get(n, 0), matrix.off_diag_default.unwrap_or(matrix.default()));
+        }
+
+        #[test]
+        fn sparse_out_of_bounds_access_returns_default_not_panic(matrix in arbitrary_sparse_matrix()) {
+            let rows = matrix.nb_rows();
+            let cols = matrix.nb_cols();
+            prop_assert_eq!(matrix.get(rows, 0), matrix.off_diag_default.unwrap_or(matrix.default()));
+            prop_assert_eq!(matrix.get(0, cols), matrix.off_diag_default.unwrap_or(matrix.default()));
+        }
+
+        #[test]
+        fn symmetric_out_of_bounds_access_returns_default_not_panic(matrix in arbitrary_symmetric_matrix()) {
+            let n = matrix.nb_rows();
+            prop_assert_eq!(matrix.get(n, 0), matrix.default());
+            prop_assert_eq!(matrix.get(0, n), matrix.default());
+        }
+
+        #[test]
+        fn dense_to_sparse_round_trip_preserves_every_entry(matrix in arbitrary_dense_matrix()) {
+            let sparse = matrix.to_sparse(0.0);
+            let round_tripped = to_dense(&sparse);
+            for i in 0..matrix.nb_rows() {
+                for j in 0..matrix.nb_cols() {
+                    prop_assert_eq!(matrix.get(i, j), round_tripped.get(i, j));
+                }
+            }
+        }
+
+        #[test]
+        fn coo_round_trip_preserves_every_entry(matrix in arbitrary_dense_matrix()) {
+            let mut builder = CooBuilder::new(matrix.nb_rows(), matrix.nb_cols(), None, None);
+            for i in 0..matrix.nb_rows() {
+                for j in 0..matrix.nb_cols() {
+                    builder.push(i, j, matrix.get(i, j)).unwrap();
+                }
+            }
+            let sparse = builder.build_csc(CooDuplicatePolicy::KeepFirst).unwrap();
+            for i in 0..matrix.nb_rows() {
+                for j in 0..matrix.nb_cols() {
+                    prop_assert_eq!(matrix.get(i, j), sparse.get(i, j));
+                }
+            }
+        }
+
+        #[test]
+        fn sparse_index_agrees_with_brute_force_scan(matrix in arbitrary_sparse_matrix()) {
+            for j in 0..matrix.nb_cols() {
+                let column = &matrix.row_indices[matrix.col_ptrs[j]..matrix.col_ptrs[j + 1]];
+                for i in 0..matrix.nb_rows() {
+                    let brute_force = column.iter().position(|&row| row == i);
+                    prop_assert_eq!(
+                        matrix.index(i, j),
+                        brute_force.map(|pos| matrix.col_ptrs[j] + pos)
+                    );
+                }
+            }
+        }
+    }
 }